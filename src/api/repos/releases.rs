@@ -1,5 +1,9 @@
 use super::*;
 
+pub mod asset;
+
+pub use asset::ReleaseAssetsHandler;
+
 /// Handler for GitHub's releases API.
 ///
 /// Created with [`RepoHandler::releases`].
@@ -54,9 +58,70 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn create<'t>(&self, tag_name: &'t (impl AsRef<str> + ?Sized)) -> CreateReleaseBuilder<'_, '_, '_, 't, '_, '_, '_> {
+    pub fn create<'t>(&self, tag_name: &'t (impl AsRef<str> + ?Sized)) -> CreateReleaseBuilder<'_, '_, '_, 't, '_, '_, '_, '_, '_> {
         CreateReleaseBuilder::new(self, tag_name.as_ref())
     }
+
+    /// Creates a new `ReleaseAssetsHandler` for working with the assets of a
+    /// release.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let assets = octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .assets();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn assets(&self) -> ReleaseAssetsHandler<'_, '_, '_> {
+        ReleaseAssetsHandler::new(self)
+    }
+
+    /// Creates a new `UpdateReleaseBuilder` for the release with `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let release = octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .update(1)
+    ///     // Optional Parameters
+    ///     .tag_name("v1.0.1")
+    ///     .target_commitish("main")
+    ///     .name("Version 1.0.1")
+    ///     .body("Announcing 1.0.1!")
+    ///     .draft(false)
+    ///     .prerelease(false)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, id: u64) -> UpdateReleaseBuilder<'_, '_, '_, '_, '_, '_, '_> {
+        UpdateReleaseBuilder::new(self, id)
+    }
+
+    /// Deletes the release with `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .delete(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, id: u64) -> crate::Result<()> {
+        let url = format!(
+            "/repos/{owner}/{repo}/releases/{id}",
+            owner = self.parent.owner,
+            repo = self.parent.repo,
+            id = id,
+        );
+        let _response = self.parent.crab._delete(url, None::<&()>).await?;
+        Ok(())
+    }
 }
 
 /// A builder pattern struct for listing releases.
@@ -104,6 +169,33 @@ impl<'octo, 'r1, 'r2> ListReleasesBuilder<'octo, 'r1, 'r2> {
         );
         self.handler.parent.crab.get(url, Some(&self)).await
     }
+
+    /// Returns a stream over every release in the repository, transparently
+    /// following the `Link` rel="next" headers so the caller doesn't have to
+    /// increment `page` by hand.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # use futures_util::TryStreamExt;
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let mut stream = octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .list()
+    ///     .stream()
+    ///     .await?;
+    /// while let Some(release) = stream.try_next().await? {
+    ///     // use `release`...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<crate::models::repos::Release>> + 'octo>
+    {
+        let crab = self.handler.parent.crab;
+        let page = self.send().await?;
+        Ok(page.into_stream(crab))
+    }
 }
 
 /// A builder pattern struct for listing releases.
@@ -112,7 +204,7 @@ impl<'octo, 'r1, 'r2> ListReleasesBuilder<'octo, 'r1, 'r2> {
 ///
 /// [`PullRequestHandler::list`]: ./struct.ReleasesHandler.html#method.list
 #[derive(serde::Serialize)]
-pub struct CreateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> {
+pub struct CreateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body, 'make_latest, 'discussion_category_name> {
     #[serde(skip)]
     handler: &'handler ReleasesHandler<'octo, 'repos>,
     tag_name: &'tag_name str,
@@ -126,9 +218,15 @@ pub struct CreateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_comm
     draft: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     prerelease: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generate_release_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    make_latest: Option<&'make_latest str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discussion_category_name: Option<&'discussion_category_name str>,
 }
 
-impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> CreateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> {
+impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body, 'make_latest, 'discussion_category_name> CreateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body, 'make_latest, 'discussion_category_name> {
     pub(crate) fn new(handler: &'handler ReleasesHandler<'octo, 'repos>, tag_name: &'tag_name str) -> Self {
         Self {
             handler,
@@ -138,6 +236,9 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> Create
             body: None,
             draft: None,
             prerelease: None,
+            generate_release_notes: None,
+            make_latest: None,
+            discussion_category_name: None,
         }
     }
 
@@ -174,6 +275,31 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> Create
         self
     }
 
+    /// Whether to automatically generate the name and body for this release.
+    /// If `name` is specified, the specified name will be used; otherwise, a
+    /// name will be automatically generated. If `body` is specified, the body
+    /// will be pre-pended to the automatically generated notes.
+    pub fn generate_release_notes(mut self, generate_release_notes: impl Into<bool>) -> Self {
+        self.generate_release_notes = Some(generate_release_notes.into());
+        self
+    }
+
+    /// Specifies whether this release should be set as the latest release for
+    /// the repository. Can be `"true"`, `"false"`, or `"legacy"` to use
+    /// GitHub's default behavior of comparing the release with all others.
+    pub fn make_latest(mut self, make_latest: &'make_latest (impl AsRef<str> + ?Sized)) -> Self {
+        self.make_latest = Some(make_latest.as_ref());
+        self
+    }
+
+    /// If specified, a discussion of the specified category is created and
+    /// linked to the release. The value must be a category that already exists
+    /// in the repository.
+    pub fn discussion_category_name(mut self, discussion_category_name: &'discussion_category_name (impl AsRef<str> + ?Sized)) -> Self {
+        self.discussion_category_name = Some(discussion_category_name.as_ref());
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::models::repos::Release> {
         let url = format!(
@@ -181,6 +307,96 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> Create
             owner = self.handler.parent.owner,
             repo = self.handler.parent.repo
         );
-        self.handler.parent.crab.get(url, Some(&self)).await
+        self.handler.parent.crab.post(url, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for updating a release.
+///
+/// created by [`ReleasesHandler::update`]
+///
+/// [`ReleasesHandler::update`]: ./struct.ReleasesHandler.html#method.update
+#[derive(serde::Serialize)]
+pub struct UpdateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> {
+    #[serde(skip)]
+    handler: &'handler ReleasesHandler<'octo, 'repos>,
+    #[serde(skip)]
+    id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag_name: Option<&'tag_name str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_commitish: Option<&'target_commitish str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'name str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<&'body str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prerelease: Option<bool>,
+}
+
+impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> UpdateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body> {
+    pub(crate) fn new(handler: &'handler ReleasesHandler<'octo, 'repos>, id: u64) -> Self {
+        Self {
+            handler,
+            id,
+            tag_name: None,
+            target_commitish: None,
+            name: None,
+            body: None,
+            draft: None,
+            prerelease: None,
+        }
+    }
+
+    /// The name of the tag.
+    pub fn tag_name(mut self, tag_name: &'tag_name (impl AsRef<str> + ?Sized)) -> Self {
+        self.tag_name = Some(tag_name.as_ref());
+        self
+    }
+
+    /// Specifies the commitish value that determines where the Git tag is
+    /// created from. Can be any branch or commit SHA. Unused if the Git tag
+    /// already exists. Default: the repository's default branch
+    /// (usually `main`).
+    pub fn target_commitish(mut self, target_commitish: &'target_commitish (impl AsRef<str> + ?Sized)) -> Self {
+        self.target_commitish = Some(target_commitish.as_ref());
+        self
+    }
+
+    /// The name of the release.
+    pub fn name(mut self, name: &'name (impl AsRef<str> + ?Sized)) -> Self {
+        self.name = Some(name.as_ref());
+        self
+    }
+
+    /// Text describing the contents of the tag.
+    pub fn body(mut self, body: &'body (impl AsRef<str> + ?Sized)) -> Self {
+        self.body = Some(body.as_ref());
+        self
+    }
+
+    /// Whether to set the release as a "draft" release or not.
+    pub fn draft(mut self, draft: impl Into<bool>) -> Self {
+        self.draft = Some(draft.into());
+        self
+    }
+
+    /// Whether to set the release as a "prerelease" or not.
+    pub fn prerelease(mut self, prerelease: impl Into<bool>) -> Self {
+        self.prerelease = Some(prerelease.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::models::repos::Release> {
+        let url = format!(
+            "/repos/{owner}/{repo}/releases/{id}",
+            owner = self.handler.parent.owner,
+            repo = self.handler.parent.repo,
+            id = self.id,
+        );
+        self.handler.parent.crab.patch(url, Some(&self)).await
     }
 }
@@ -0,0 +1,341 @@
+use super::ReleasesHandler;
+use crate::FromResponse;
+use futures_util::TryStreamExt;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+/// Handler for GitHub's release assets API.
+///
+/// Created with [`ReleasesHandler::assets`].
+///
+/// [`ReleasesHandler::assets`]: ./struct.ReleasesHandler.html#method.assets
+pub struct ReleaseAssetsHandler<'octo, 'r1, 'r2> {
+    handler: &'r2 ReleasesHandler<'octo, 'r1>,
+}
+
+impl<'octo, 'r1, 'r2> ReleaseAssetsHandler<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 ReleasesHandler<'octo, 'r1>) -> Self {
+        Self { handler }
+    }
+
+    /// Creates a new `ListReleaseAssetsBuilder` that can be configured to list
+    /// the assets of the release with `release_id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let page = octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .assets()
+    ///     .list(42)
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .page(5u32)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self, release_id: u64) -> ListReleaseAssetsBuilder<'_, '_, '_> {
+        ListReleaseAssetsBuilder::new(self, release_id)
+    }
+
+    /// Gets the release asset with `asset_id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let asset = octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .assets()
+    ///     .get(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, asset_id: u64) -> crate::Result<crate::models::repos::Asset> {
+        let url = format!(
+            "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+            owner = self.handler.parent.owner,
+            repo = self.handler.parent.repo,
+            asset_id = asset_id,
+        );
+        self.handler.parent.crab.get(url, None::<&()>).await
+    }
+
+    /// Creates a new `UploadReleaseAssetBuilder` that uploads the raw `data` as
+    /// a new asset on the release with `release_id`, hitting the
+    /// `uploads.github.com` host with the caller-supplied `content_type`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let bytes = std::fs::read("example.tar.gz")?;
+    /// let asset = octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .assets()
+    ///     .upload(42, "example.tar.gz", "application/gzip", bytes)
+    ///     // Optional Parameters
+    ///     .label("The release tarball")
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload<'name, 'content_type>(
+        &self,
+        release_id: u64,
+        name: &'name (impl AsRef<str> + ?Sized),
+        content_type: &'content_type (impl AsRef<str> + ?Sized),
+        data: impl Into<reqwest::Body>,
+    ) -> UploadReleaseAssetBuilder<'_, '_, '_, 'name, 'content_type, '_> {
+        UploadReleaseAssetBuilder::new(self, release_id, name.as_ref(), content_type.as_ref(), data.into())
+    }
+
+    /// Creates a new `UpdateReleaseAssetBuilder` to edit the name and/or label
+    /// of the asset with `asset_id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let asset = octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .assets()
+    ///     .update(1)
+    ///     .name("renamed.tar.gz")
+    ///     .label("The release tarball")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, asset_id: u64) -> UpdateReleaseAssetBuilder<'_, '_, '_, '_, '_> {
+        UpdateReleaseAssetBuilder::new(self, asset_id)
+    }
+
+    /// Deletes the release asset with `asset_id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .assets()
+    ///     .delete(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, asset_id: u64) -> crate::Result<()> {
+        let url = format!(
+            "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+            owner = self.handler.parent.owner,
+            repo = self.handler.parent.repo,
+            asset_id = asset_id,
+        );
+        let _response = self.handler.parent.crab._delete(url, None::<&()>).await?;
+        Ok(())
+    }
+
+    /// Streams the contents of the asset with `asset_id` without buffering the
+    /// whole binary in memory, following the asset's redirect to its download
+    /// location.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # use futures_util::StreamExt;
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let mut stream = octocrab.repos("owner", "repo")
+    ///     .releases()
+    ///     .assets()
+    ///     .stream(1)
+    ///     .await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     let chunk = chunk?;
+    ///     // write `chunk` to disk...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream(
+        &self,
+        asset_id: u64,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<bytes::Bytes>>> {
+        let url = format!(
+            "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+            owner = self.handler.parent.owner,
+            repo = self.handler.parent.repo,
+            asset_id = asset_id,
+        );
+        let request = self
+            .handler
+            .parent
+            .crab
+            .request_builder(self.handler.parent.crab.absolute_url(url)?, reqwest::Method::GET)
+            .header(reqwest::header::ACCEPT, "application/octet-stream");
+        let response = self.handler.parent.crab.execute(request).await?;
+        let response = crate::map_github_error(response).await?;
+        Ok(response.bytes_stream().map_err(crate::Error::from))
+    }
+}
+
+/// A builder pattern struct for listing the assets of a release.
+///
+/// created by [`ReleaseAssetsHandler::list`]
+///
+/// [`ReleaseAssetsHandler::list`]: ./struct.ReleaseAssetsHandler.html#method.list
+#[derive(serde::Serialize)]
+pub struct ListReleaseAssetsBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 ReleaseAssetsHandler<'octo, 'r1, 'r2>,
+    #[serde(skip)]
+    release_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r1, 'r2> ListReleaseAssetsBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 ReleaseAssetsHandler<'octo, 'r1, 'r2>, release_id: u64) -> Self {
+        Self {
+            handler,
+            release_id,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<crate::models::repos::Asset>> {
+        let url = format!(
+            "/repos/{owner}/{repo}/releases/{release_id}/assets",
+            owner = self.handler.handler.parent.owner,
+            repo = self.handler.handler.parent.repo,
+            release_id = self.release_id,
+        );
+        self.handler.handler.parent.crab.get(url, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for updating a release asset.
+///
+/// created by [`ReleaseAssetsHandler::update`]
+///
+/// [`ReleaseAssetsHandler::update`]: ./struct.ReleaseAssetsHandler.html#method.update
+#[derive(serde::Serialize)]
+pub struct UpdateReleaseAssetBuilder<'octo, 'r1, 'r2, 'name, 'label> {
+    #[serde(skip)]
+    handler: &'r2 ReleaseAssetsHandler<'octo, 'r1, 'r2>,
+    #[serde(skip)]
+    asset_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'name str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<&'label str>,
+}
+
+impl<'octo, 'r1, 'r2, 'name, 'label> UpdateReleaseAssetBuilder<'octo, 'r1, 'r2, 'name, 'label> {
+    pub(crate) fn new(handler: &'r2 ReleaseAssetsHandler<'octo, 'r1, 'r2>, asset_id: u64) -> Self {
+        Self {
+            handler,
+            asset_id,
+            name: None,
+            label: None,
+        }
+    }
+
+    /// The file name of the asset.
+    pub fn name(mut self, name: &'name (impl AsRef<str> + ?Sized)) -> Self {
+        self.name = Some(name.as_ref());
+        self
+    }
+
+    /// An alternate short description of the asset.
+    pub fn label(mut self, label: &'label (impl AsRef<str> + ?Sized)) -> Self {
+        self.label = Some(label.as_ref());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::models::repos::Asset> {
+        let url = format!(
+            "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+            owner = self.handler.handler.parent.owner,
+            repo = self.handler.handler.parent.repo,
+            asset_id = self.asset_id,
+        );
+        self.handler.handler.parent.crab.patch(url, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for uploading a release asset.
+///
+/// created by [`ReleaseAssetsHandler::upload`]
+///
+/// [`ReleaseAssetsHandler::upload`]: ./struct.ReleaseAssetsHandler.html#method.upload
+pub struct UploadReleaseAssetBuilder<'octo, 'r1, 'r2, 'name, 'content_type, 'label> {
+    handler: &'r2 ReleaseAssetsHandler<'octo, 'r1, 'r2>,
+    release_id: u64,
+    name: &'name str,
+    content_type: &'content_type str,
+    label: Option<&'label str>,
+    data: reqwest::Body,
+}
+
+impl<'octo, 'r1, 'r2, 'name, 'content_type, 'label> UploadReleaseAssetBuilder<'octo, 'r1, 'r2, 'name, 'content_type, 'label> {
+    pub(crate) fn new(
+        handler: &'r2 ReleaseAssetsHandler<'octo, 'r1, 'r2>,
+        release_id: u64,
+        name: &'name str,
+        content_type: &'content_type str,
+        data: reqwest::Body,
+    ) -> Self {
+        Self {
+            handler,
+            release_id,
+            name,
+            content_type,
+            label: None,
+            data,
+        }
+    }
+
+    /// An alternate short description of the asset.
+    pub fn label(mut self, label: &'label (impl AsRef<str> + ?Sized)) -> Self {
+        self.label = Some(label.as_ref());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::models::repos::Asset> {
+        let mut url = format!(
+            "https://uploads.github.com/repos/{owner}/{repo}/releases/{release_id}/assets?name={name}",
+            owner = self.handler.handler.parent.owner,
+            repo = self.handler.handler.parent.repo,
+            release_id = self.release_id,
+            name = utf8_percent_encode(self.name, NON_ALPHANUMERIC),
+        );
+        if let Some(label) = self.label {
+            url.push_str(&format!(
+                "&label={label}",
+                label = utf8_percent_encode(label, NON_ALPHANUMERIC),
+            ));
+        }
+        let crab = self.handler.handler.parent.crab;
+        let request = crab
+            .request_builder(url, reqwest::Method::POST)
+            .header(reqwest::header::CONTENT_TYPE, self.content_type)
+            .body(self.data);
+        let response = crab.execute(request).await?;
+        let response = crate::map_github_error(response).await?;
+        <crate::models::repos::Asset>::from_response(response).await
+    }
+}
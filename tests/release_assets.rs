@@ -0,0 +1,96 @@
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn getting_an_asset_hits_the_assets_endpoint() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/assets/1")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let _ = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .assets()
+        .get(1)
+        .await;
+}
+
+#[tokio::test]
+async fn listing_assets_is_keyed_by_release_id() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/42/assets")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let _ = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .assets()
+        .list(42)
+        .send()
+        .await;
+}
+
+#[tokio::test]
+async fn updating_an_asset_issues_a_patch_with_name_and_label() {
+    let server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/assets/1")))
+        .and(body_partial_json(serde_json::json!({
+            "name": "renamed.tar.gz",
+            "label": "The release tarball",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let _ = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .assets()
+        .update(1)
+        .name("renamed.tar.gz")
+        .label("The release tarball")
+        .send()
+        .await;
+}
+
+#[tokio::test]
+async fn deleting_an_asset_issues_a_delete() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/assets/1")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let _ = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .assets()
+        .delete(1)
+        .await;
+}
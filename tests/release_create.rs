@@ -0,0 +1,57 @@
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn creating_a_release_issues_a_post() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let _ = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .create("v1.0.0")
+        .send()
+        .await;
+}
+
+#[tokio::test]
+async fn create_sends_generate_release_notes() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+        .and(body_partial_json(serde_json::json!({
+            "tag_name": "v1.0.0",
+            "generate_release_notes": true,
+            "make_latest": "true",
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let _ = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .create("v1.0.0")
+        .generate_release_notes(true)
+        .make_latest("true")
+        .send()
+        .await;
+}
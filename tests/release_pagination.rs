@@ -0,0 +1,48 @@
+use futures_util::StreamExt;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn stream_follows_the_next_link() {
+    let server = MockServer::start().await;
+    let next = format!("<{}/next_page>; rel=\"next\"", server.uri());
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("link", next.as_str())
+                .set_body_json(serde_json::json!([])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/next_page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let mut stream = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .list()
+        .stream()
+        .await
+        .unwrap();
+
+    while stream.next().await.is_some() {}
+}
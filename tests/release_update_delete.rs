@@ -0,0 +1,55 @@
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn updating_a_release_issues_a_patch() {
+    let server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/1")))
+        .and(body_partial_json(serde_json::json!({
+            "tag_name": "v1.0.1",
+            "name": "Version 1.0.1",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let _ = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .update(1)
+        .tag_name("v1.0.1")
+        .name("Version 1.0.1")
+        .send()
+        .await;
+}
+
+#[tokio::test]
+async fn deleting_a_release_issues_a_delete() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/1")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let octocrab = setup_octocrab(&server.uri());
+    let _ = octocrab
+        .repos(OWNER, REPO)
+        .releases()
+        .delete(1)
+        .await;
+}